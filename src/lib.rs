@@ -1,30 +1,35 @@
 //! Iterator zippers
 //!
-//! This crate provides "zippers" as tuple structs: `Zip2`, `Zip3`, etc. These structs accept
-//! iterators as fields, and provides iteration over the "zipped" elements.
-//!
-//! The functionality is similar to the `Iterator::zip` method. The difference is that the `zip`
-//! method always produces an iterator that yields *two-element* tuples. If you want to zip 3
-//! iterators, you'll write `xs.zip(ys).zip(zs)` which returns an iterator that yields elements of
-//! type `((X, Y), Z)`. On the other hand, with this library you can write `Zip3(xs, ys, zs)` which
-//! is an iterator that yields elements of type `(X, Y, Z)`.
+//! This crate provides a `Zip<T>` iterator that zips together a tuple `T` of iterators. The
+//! functionality is similar to the `Iterator::zip` method. The difference is that `zip` always
+//! produces an iterator that yields *two-element* tuples. If you want to zip 3 iterators, you'll
+//! write `xs.zip(ys).zip(zs)` which returns an iterator that yields elements of type
+//! `((X, Y), Z)`. On the other hand, with this library you can write `multizip((xs, ys, zs))`
+//! which is an iterator that yields elements of type `(X, Y, Z)`.
 //!
 //! ```
-//! use zip::Zip3;
+//! use zip::multizip;
 //!
 //! let chars = ['a', 'b', 'c'];
 //! let mut v = vec![0, 1, 2];
-//! for (&c, i, &mut j) in Zip3(chars.iter(), 0i32..5, v.iter_mut()) {
+//! for (&c, i, &mut j) in multizip((chars.iter(), 0i32..5, v.iter_mut())) {
 //!     assert!(i < 3);
 //!     assert_eq!(i, j);
 //! }
 //! ```
 
+#![feature(specialization)]
+#![allow(incomplete_features)]
 #![cfg_attr(test, feature(test))]
 #![deny(missing_docs, warnings)]
 
 /// This macro emulates an "any-arity" free function that zips iterators
 ///
+/// From 2 up to 26 arguments are accepted; 27 or more do not compile. With three or more
+/// arguments, this macro folds them into nested two-iterator zips, and flattens the resulting
+/// nested tuples back into a single flat tuple, so the yielded `Item` is always a flat tuple,
+/// including past the 12 iterators that `multizip` accepts directly.
+///
 /// # Examples
 ///
 /// ```
@@ -43,37 +48,19 @@
 /// ```
 #[macro_export]
 macro_rules! zip {
+    ($($x:expr),+,) => { zip!($($x),+) };
     ($a:expr, $b:expr) => {
-        $crate::Zip2(
-            ::std::iter::IntoIterator::into_iter($a),
-            ::std::iter::IntoIterator::into_iter($b),
-        )
+        $crate::multizip(($a, $b))
     };
-    ($a:expr, $b:expr, $c:expr) => {
-        $crate::Zip3(
-            ::std::iter::IntoIterator::into_iter($a),
-            ::std::iter::IntoIterator::into_iter($b),
-            ::std::iter::IntoIterator::into_iter($c),
-        )
+    ($a:expr, $b:expr, $($rest:expr),+) => {
+        zip!(@fold $crate::multizip(($a, $b)), $($rest),+)
     };
-    ($a:expr, $b:expr, $c:expr, $d:expr) => {
-        $crate::Zip4(
-            ::std::iter::IntoIterator::into_iter($a),
-            ::std::iter::IntoIterator::into_iter($b),
-            ::std::iter::IntoIterator::into_iter($c),
-            ::std::iter::IntoIterator::into_iter($d),
-        )
+    (@fold $acc:expr, $x:expr) => {
+        $crate::Flatten($crate::multizip(($acc, $x)))
     };
-    ($a:expr, $b:expr, $c:expr, $d:expr, $e:expr) => {
-        $crate::Zip5(
-            ::std::iter::IntoIterator::into_iter($a),
-            ::std::iter::IntoIterator::into_iter($b),
-            ::std::iter::IntoIterator::into_iter($c),
-            ::std::iter::IntoIterator::into_iter($d),
-            ::std::iter::IntoIterator::into_iter($e),
-        )
+    (@fold $acc:expr, $x:expr, $($rest:expr),+) => {
+        zip!(@fold $crate::Flatten($crate::multizip(($acc, $x))), $($rest),+)
     };
-    ($($x:expr),+,) => { zip!($($x),+) }
 }
 
 macro_rules! min {
@@ -82,149 +69,360 @@ macro_rules! min {
     ($($x:expr),+,) => { min!($($x),+) };
 }
 
-/// Two-iterator zipper
-pub struct Zip2<A, B>(pub A, pub B) where
-    A: Iterator,
-    B: Iterator;
+macro_rules! fold_size_hints {
+    ($x:expr) => { $x };
+    ($x:expr, $($y:expr),+) => { size_hint::min($x, fold_size_hints!($($y),+)) };
+}
 
-impl<A, B> Iterator for Zip2<A, B> where
-    A: Iterator,
-    B: Iterator,
-{
-    type Item = (A::Item, B::Item);
+/// Helpers for combining the `size_hint`s of several iterators
+mod size_hint {
+    use std::cmp;
+
+    /// Combines two `size_hint`s into the `size_hint` of their zip
+    ///
+    /// The resulting lower bound is the min of the two lower bounds. The resulting upper bound
+    /// is the min of the two upper bounds, where a `None` upper bound is treated as "unbounded"
+    /// and doesn't by itself force the result to `None`.
+    pub fn min(a: (usize, Option<usize>), b: (usize, Option<usize>)) -> (usize, Option<usize>) {
+        let (a_lower, a_upper) = a;
+        let (b_lower, b_upper) = b;
+
+        let lower = cmp::min(a_lower, b_lower);
+
+        let upper = match (a_upper, b_upper) {
+            (Some(x), Some(y)) => Some(cmp::min(x, y)),
+            (Some(x), None) => Some(x),
+            (None, Some(y)) => Some(y),
+            (None, None) => None,
+        };
+
+        (lower, upper)
+    }
+}
 
-    fn next(&mut self) -> Option<(A::Item, B::Item)> {
-        if let Some(a) = self.0.next() {
-            if let Some(b) = self.1.next() {
-                return Some((a, b));
-            }
-        }
+/// A tuple of `IntoIterator`s that can be turned into a tuple of `Iterator`s
+///
+/// This is an implementation detail of `multizip`, and is not meant to be used directly. It's
+/// `pub` only because it appears in `multizip`'s signature.
+pub trait IntoIteratorTuple {
+    /// The tuple of iterators that this tuple of `IntoIterator`s turns into
+    type Output;
+
+    /// Calls `into_iter()` on every element of the tuple
+    fn into_iterator_tuple(self) -> Self::Output;
+}
+
+/// A "zipper" that zips together the iterators held in the tuple `T`
+///
+/// Use `multizip` to construct a `Zip`.
+pub struct Zip<T> {
+    t: T,
+}
+
+/// Zips a tuple `t` of iterators (or, more generally, `IntoIterator`s)
+///
+/// This is the any-arity counterpart of `Iterator::zip`: `multizip((xs, ys, zs))` yields
+/// `(X, Y, Z)` tuples, instead of the `((X, Y), Z)` tuples that `xs.zip(ys).zip(zs)` yields.
+pub fn multizip<T>(t: T) -> Zip<T::Output> where T: IntoIteratorTuple {
+    Zip { t: t.into_iterator_tuple() }
+}
+
+/// A tuple that can grow by one element
+///
+/// This is an implementation detail of the `zip!` macro, and is not meant to be used directly.
+/// It's `pub` only because it appears in `Flatten`'s trait impls.
+pub trait TupleAppend<Z> {
+    /// The tuple that results from appending a `Z` to `Self`
+    type Output;
+
+    /// Appends `z` to the end of this tuple
+    fn tuple_append(self, z: Z) -> Self::Output;
+}
+
+/// Flattens the `(T, Z)` pairs yielded by its inner iterator into a single flat tuple
+///
+/// `zip!` uses this to fold any number of iterators into nested two-iterator `Zip`s while still
+/// yielding a flat tuple, the way `multizip` does. The field is `pub` because the `zip!` macro
+/// expands to `Flatten(...)` at the call site, which may be in a downstream crate.
+pub struct Flatten<I>(pub I);
+
+impl<I, T, Z> Iterator for Flatten<I> where
+    I: Iterator<Item = (T, Z)>,
+    T: TupleAppend<Z>,
+{
+    type Item = T::Output;
 
-        None
+    fn next(&mut self) -> Option<T::Output> {
+        self.0.next().map(|(t, z)| t.tuple_append(z))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (min! {
-            self.0.size_hint().0,
-            self.1.size_hint().0,
-        }, None)
+        self.0.size_hint()
     }
 }
 
-/// Three-iterator zipper
-pub struct Zip3<A, B, C>(pub A, pub B, pub C) where
-    A: Iterator,
-    B: Iterator,
-    C: Iterator;
+impl<I, T, Z> DoubleEndedIterator for Flatten<I> where
+    I: DoubleEndedIterator<Item = (T, Z)>,
+    T: TupleAppend<Z>,
+{
+    fn next_back(&mut self) -> Option<T::Output> {
+        self.0.next_back().map(|(t, z)| t.tuple_append(z))
+    }
+}
 
-impl<A, B, C> Iterator for Zip3<A, B, C> where
-    A: Iterator,
-    B: Iterator,
-    C: Iterator,
+impl<I, T, Z> ExactSizeIterator for Flatten<I> where
+    I: ExactSizeIterator<Item = (T, Z)>,
+    T: TupleAppend<Z>,
 {
-    type Item = (A::Item, B::Item, C::Item);
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
 
-    fn next(&mut self) -> Option<(A::Item, B::Item, C::Item)> {
-        if let Some(a) = self.0.next() {
-            if let Some(b) = self.1.next() {
-                if let Some(c) = self.2.next() {
-                    return Some((a, b, c));
-                }
+macro_rules! impl_tuple_append {
+    ($($A:ident),+) => {
+        impl<$($A,)+ Z> TupleAppend<Z> for ($($A,)+) {
+            type Output = ($($A,)+ Z);
+
+            #[allow(non_snake_case)]
+            fn tuple_append(self, z: Z) -> Self::Output {
+                let ($($A,)+) = self;
+
+                ($($A,)+ z)
             }
         }
+    }
+}
 
-        None
+impl_tuple_append!(A);
+impl_tuple_append!(A, B);
+impl_tuple_append!(A, B, C);
+impl_tuple_append!(A, B, C, D);
+impl_tuple_append!(A, B, C, D, E);
+impl_tuple_append!(A, B, C, D, E, F);
+impl_tuple_append!(A, B, C, D, E, F, G);
+impl_tuple_append!(A, B, C, D, E, F, G, H);
+impl_tuple_append!(A, B, C, D, E, F, G, H, I);
+impl_tuple_append!(A, B, C, D, E, F, G, H, I, J);
+impl_tuple_append!(A, B, C, D, E, F, G, H, I, J, K);
+impl_tuple_append!(A, B, C, D, E, F, G, H, I, J, K, L);
+impl_tuple_append!(A, B, C, D, E, F, G, H, I, J, K, L, M);
+impl_tuple_append!(A, B, C, D, E, F, G, H, I, J, K, L, M, N);
+impl_tuple_append!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O);
+impl_tuple_append!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P);
+impl_tuple_append!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q);
+impl_tuple_append!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R);
+impl_tuple_append!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S);
+impl_tuple_append!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T);
+impl_tuple_append!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U);
+impl_tuple_append!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V);
+impl_tuple_append!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W);
+impl_tuple_append!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W, X);
+impl_tuple_append!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W, X, Y);
+
+macro_rules! impl_into_iterator_tuple {
+    ($($A:ident),+) => {
+        impl<$($A),+> IntoIteratorTuple for ($($A,)+) where
+            $($A: IntoIterator,)+
+        {
+            type Output = ($($A::IntoIter,)+);
+
+            #[allow(non_snake_case)]
+            fn into_iterator_tuple(self) -> Self::Output {
+                let ($($A,)+) = self;
+
+                ($($A.into_iter(),)+)
+            }
+        }
     }
+}
 
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        (min!{
-            self.0.size_hint().0,
-            self.1.size_hint().0,
-            self.2.size_hint().0,
-        }, None)
+macro_rules! impl_zip_iterator {
+    ($($A:ident),+) => {
+        impl<$($A),+> Iterator for Zip<($($A,)+)> where
+            $($A: Iterator,)+
+        {
+            type Item = ($($A::Item,)+);
+
+            #[allow(non_snake_case)]
+            default fn next(&mut self) -> Option<Self::Item> {
+                let ($(ref mut $A,)+) = self.t;
+
+                $(let $A = match $A.next() {
+                    Some(x) => x,
+                    None => return None,
+                };)+
+
+                Some(($($A,)+))
+            }
+
+            #[allow(non_snake_case)]
+            default fn size_hint(&self) -> (usize, Option<usize>) {
+                let ($(ref $A,)+) = self.t;
+
+                fold_size_hints!($($A.size_hint()),+)
+            }
+        }
     }
 }
 
-/// Four-iterator zipper
-pub struct Zip4<A, B, C, D>(pub A, pub B, pub C, pub D) where
-    A: Iterator,
-    B: Iterator,
-    C: Iterator,
-    D: Iterator;
-
-impl<A, B, C, D> Iterator for Zip4<A, B, C, D> where
-    A: Iterator,
-    B: Iterator,
-    C: Iterator,
-    D: Iterator,
-{
-    type Item = (A::Item, B::Item, C::Item, D::Item);
-
-    fn next(&mut self) -> Option<(A::Item, B::Item, C::Item, D::Item)> {
-        if let Some(a) = self.0.next() {
-            if let Some(b) = self.1.next() {
-                if let Some(c) = self.2.next() {
-                    if let Some(d) = self.3.next() {
-                        return Some((a, b, c, d))
-                    }
+// Specializes `next` for the case where every component is an `ExactSizeIterator`: the shared
+// remaining length is read straight from the components (`len()` is O(1) on `ExactSizeIterator`),
+// so once it's zero the result is `None` without probing any component, and while it's nonzero
+// every component is known (by the `ExactSizeIterator` invariant) to yield `Some`. The length is
+// recomputed from the components on every call, rather than cached on `Zip`, because `next_back`
+// (see `impl_zip_double_ended_iterator!`) also consumes these same components and a cache would
+// drift out of sync with it. This mirrors how the standard library's `Zip` specializes for
+// `TrustedLen`/`ExactSizeIterator` to remove the per-element branching that the general `next`
+// above has to do.
+macro_rules! impl_zip_iterator_fast {
+    ($($A:ident),+) => {
+        impl<$($A),+> Iterator for Zip<($($A,)+)> where
+            $($A: ExactSizeIterator,)+
+        {
+            #[allow(non_snake_case)]
+            fn next(&mut self) -> Option<Self::Item> {
+                let ($(ref mut $A,)+) = self.t;
+
+                if min!($($A.len()),+) == 0 {
+                    return None;
                 }
+
+                Some(($($A.next().unwrap(),)+))
             }
         }
-
-        None
     }
+}
 
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        (min!{
-            self.0.size_hint().0,
-            self.1.size_hint().0,
-            self.2.size_hint().0,
-            self.3.size_hint().0,
-        }, None)
+macro_rules! impl_zip_exact_size_iterator {
+    ($($A:ident),+) => {
+        impl<$($A),+> ExactSizeIterator for Zip<($($A,)+)> where
+            $($A: ExactSizeIterator,)+
+        {
+            #[allow(non_snake_case)]
+            fn len(&self) -> usize {
+                let ($(ref $A,)+) = self.t;
+
+                min!($($A.len()),+)
+            }
+        }
     }
 }
 
-/// Five-iterator zipper
-pub struct Zip5<A, B, C, D, E>(pub A, pub B, pub C, pub D, pub E) where
-    A: Iterator,
-    B: Iterator,
-    C: Iterator,
-    D: Iterator,
-    E: Iterator;
-
-impl<A, B, C, D, E> Iterator for Zip5<A, B, C, D, E> where
-    A: Iterator,
-    B: Iterator,
-    C: Iterator,
-    D: Iterator,
-    E: Iterator,
-{
-    type Item = (A::Item, B::Item, C::Item, D::Item, E::Item);
-
-    fn next(&mut self) -> Option<(A::Item, B::Item, C::Item, D::Item, E::Item)> {
-        if let Some(a) = self.0.next() {
-            if let Some(b) = self.1.next() {
-                if let Some(c) = self.2.next() {
-                    if let Some(d) = self.3.next() {
-                        if let Some(e) = self.4.next() {
-                            return Some((a, b, c, d, e))
-                        }
+macro_rules! impl_zip_double_ended_iterator {
+    ($($A:ident),+) => {
+        impl<$($A),+> DoubleEndedIterator for Zip<($($A,)+)> where
+            $($A: DoubleEndedIterator + ExactSizeIterator,)+
+        {
+            #[allow(non_snake_case)]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                let ($(ref mut $A,)+) = self.t;
+
+                // Align the tails: discard the surplus elements of the components that are
+                // longer than the shortest one, so that every component shares a common tail
+                // boundary.
+                let n = min!($($A.len()),+);
+
+                $(while $A.len() > n {
+                    if $A.next_back().is_none() {
+                        return None;
                     }
-                }
+                })+
+
+                $(let $A = match $A.next_back() {
+                    Some(x) => x,
+                    None => return None,
+                };)+
+
+                Some(($($A,)+))
             }
         }
+    }
+}
 
-        None
+macro_rules! impl_zip {
+    ($($A:ident),+) => {
+        impl_into_iterator_tuple!($($A),+);
+        impl_zip_iterator!($($A),+);
+        impl_zip_iterator_fast!($($A),+);
+        impl_zip_exact_size_iterator!($($A),+);
+        impl_zip_double_ended_iterator!($($A),+);
     }
+}
 
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        (min!{
-            self.0.size_hint().0,
-            self.1.size_hint().0,
-            self.2.size_hint().0,
-            self.3.size_hint().0,
-            self.4.size_hint().0,
-        }, None)
+impl_zip!(A, B);
+impl_zip!(A, B, C);
+impl_zip!(A, B, C, D);
+impl_zip!(A, B, C, D, E);
+impl_zip!(A, B, C, D, E, F);
+impl_zip!(A, B, C, D, E, F, G);
+impl_zip!(A, B, C, D, E, F, G, H);
+impl_zip!(A, B, C, D, E, F, G, H, I);
+impl_zip!(A, B, C, D, E, F, G, H, I, J);
+impl_zip!(A, B, C, D, E, F, G, H, I, J, K);
+impl_zip!(A, B, C, D, E, F, G, H, I, J, K, L);
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn double_ended_unequal_lengths() {
+        let a = [0, 1, 2, 3, 4];
+        let b = [10, 11, 12];
+
+        let mut z = ::multizip((a.iter(), b.iter()));
+
+        assert_eq!(z.next_back(), Some((&2, &12)));
+        assert_eq!(z.next(), Some((&0, &10)));
+        assert_eq!(z.next_back(), Some((&1, &11)));
+        assert_eq!(z.next(), None);
+        assert_eq!(z.next_back(), None);
+    }
+
+    #[test]
+    fn double_ended_pure_backward() {
+        let a = [0, 1, 2];
+        let b = [10, 11, 12, 13, 14];
+        let c = [20, 21, 22, 23];
+
+        let mut z = ::multizip((a.iter(), b.iter(), c.iter()));
+
+        assert_eq!(z.next_back(), Some((&2, &12, &22)));
+        assert_eq!(z.next_back(), Some((&1, &11, &21)));
+        assert_eq!(z.next_back(), Some((&0, &10, &20)));
+        assert_eq!(z.next_back(), None);
+    }
+
+    #[test]
+    fn double_ended_interleaved_exact_size() {
+        // All components are `ExactSizeIterator`, so `next()` here goes through the specialized
+        // fast path (`impl_zip_iterator_fast!`), interleaved with `next_back()`. This exercises
+        // the two paths sharing the same components without drifting out of sync.
+        let a = [0, 1, 2, 3, 4, 5];
+        let b = [10, 11, 12, 13, 14, 15];
+
+        let mut z = ::multizip((a.iter(), b.iter()));
+
+        assert_eq!(z.next(), Some((&0, &10)));
+        assert_eq!(z.next_back(), Some((&5, &15)));
+        assert_eq!(z.next(), Some((&1, &11)));
+        assert_eq!(z.next_back(), Some((&4, &14)));
+        assert_eq!(z.next(), Some((&2, &12)));
+        assert_eq!(z.next_back(), Some((&3, &13)));
+        assert_eq!(z.next(), None);
+        assert_eq!(z.next_back(), None);
+    }
+
+    #[test]
+    fn zip_macro_past_direct_arity() {
+        // 13 iterators: one more than `multizip` supports directly. `zip!` folds them into
+        // nested two-iterator zips and flattens the result. Each 13-tuple is turned into an array
+        // because tuples that long implement neither `PartialEq` nor `Debug`.
+        let v: Vec<_> = zip!(
+            0..1, 0..1, 0..1, 0..1, 0..1, 0..1, 0..1, 0..1, 0..1, 0..1, 0..1, 0..1, 0..1
+        ).map(|(a, b, c, d, e, f, g, h, i, j, k, l, m)| [a, b, c, d, e, f, g, h, i, j, k, l, m])
+            .collect();
+
+        assert_eq!(v, vec![[0; 13]]);
     }
 }
 
@@ -242,7 +440,7 @@ mod bench {
         let b = a;
 
         z.iter(|| {
-            ::Zip2(a.iter(), b.iter()).collect::<Vec<_>>()
+            ::multizip((a.iter(), b.iter())).collect::<Vec<_>>()
         })
     }
 
@@ -262,7 +460,7 @@ mod bench {
         let b = a;
 
         z.iter(|| {
-            ::Zip2(a.iter(), b.iter()).count()
+            ::multizip((a.iter(), b.iter())).count()
         })
     }
 
@@ -276,13 +474,36 @@ mod bench {
         })
     }
 
+    // Slice iterators are `ExactSizeIterator`, so `collect2`/`count2` above already exercise the
+    // fast path. These benchmarks force the non-`ExactSizeIterator` fallback (via `Filter`) to
+    // measure the win of the fast path against it.
+    #[bench]
+    fn collect2_slow(z: &mut Bencher) {
+        let a = [0u8; SIZE];
+        let b = a;
+
+        z.iter(|| {
+            ::multizip((a.iter().filter(|_| true), b.iter().filter(|_| true))).collect::<Vec<_>>()
+        })
+    }
+
+    #[bench]
+    fn count2_slow(z: &mut Bencher) {
+        let a = [0u8; SIZE];
+        let b = a;
+
+        z.iter(|| {
+            ::multizip((a.iter().filter(|_| true), b.iter().filter(|_| true))).count()
+        })
+    }
+
     #[bench]
     fn collect3(z: &mut Bencher) {
         let a = [0u8; SIZE];
         let (b, c) = (a, a);
 
         z.iter(|| {
-            ::Zip3(a.iter(), b.iter(), c.iter()).collect::<Vec<_>>()
+            ::multizip((a.iter(), b.iter(), c.iter())).collect::<Vec<_>>()
         })
     }
 
@@ -302,7 +523,7 @@ mod bench {
         let (b, c) = (a, a);
 
         z.iter(|| {
-            ::Zip3(a.iter(), b.iter(), c.iter()).count()
+            ::multizip((a.iter(), b.iter(), c.iter())).count()
         })
     }
 
@@ -322,7 +543,7 @@ mod bench {
         let (b, c, d) = (a, a, a);
 
         z.iter(|| {
-            ::Zip4(a.iter(), b.iter(), c.iter(), d.iter()).collect::<Vec<_>>()
+            ::multizip((a.iter(), b.iter(), c.iter(), d.iter())).collect::<Vec<_>>()
         })
     }
 
@@ -342,7 +563,7 @@ mod bench {
         let (b, c, d) = (a, a, a);
 
         z.iter(|| {
-            ::Zip4(a.iter(), b.iter(), c.iter(), d.iter()).count()
+            ::multizip((a.iter(), b.iter(), c.iter(), d.iter())).count()
         })
     }
 
@@ -362,7 +583,7 @@ mod bench {
         let (b, c, d, e) = (a, a, a, a);
 
         z.iter(|| {
-            ::Zip5(a.iter(), b.iter(), c.iter(), d.iter(), e.iter()).collect::<Vec<_>>()
+            ::multizip((a.iter(), b.iter(), c.iter(), d.iter(), e.iter())).collect::<Vec<_>>()
         })
     }
 
@@ -382,7 +603,7 @@ mod bench {
         let (b, c, d, e) = (a, a, a, a);
 
         z.iter(|| {
-            ::Zip5(a.iter(), b.iter(), c.iter(), d.iter(), e.iter()).count()
+            ::multizip((a.iter(), b.iter(), c.iter(), d.iter(), e.iter())).count()
         })
     }
 